@@ -2,6 +2,7 @@ use botao::fields::enum_fields;
 use botao::fields::format_fields;
 use botao::fields::format_fields_from_iter;
 use botao::fields::format_fields_with;
+use botao::text::enum_subfields;
 
 fn test_enum_fields(delim: u8, record: &str) {
     println!("delim : {:?}", delim as char);
@@ -22,6 +23,14 @@ fn test_nested(delim: u8, record: &str) {
     }
 }
 
+fn test_enum_subfields(delim: u8, record: &str) {
+    println!("SUBFIELDS");
+    println!("record: {:?}", record);
+    for subfields in enum_subfields(b';', delim, record) {
+        println!("{:?}", subfields);
+    }
+}
+
 fn main() {
     test_enum_fields(b',', "10, 20, 30, 40");
     test_enum_fields(b',', "10 , 20  , 30   , 40    ");
@@ -33,6 +42,9 @@ fn main() {
     test_nested(b',', "10, 20, 30, 40; 3.4");
     test_nested(b',', "10, 20, 30, 40,; 3.4");
     test_nested(b' ', "10 20 30   40  ; 3.4");
+    test_enum_subfields(b',', "10, 20, 30, 40; 3.4");
+    test_enum_subfields(b',', "10, 20, 30, 40,; 3.4");
+    test_enum_subfields(b' ', "10 20 30   40  ; 3.4");
     test_enum_fields(b',', "\n");
     test_enum_fields(b' ', "     \n");
     test_enum_fields(b',', ",\n");
@@ -131,3 +131,22 @@ where
         None => {},
     }
 }
+
+/// Formats a structured record, joining each field's subfields with
+/// `subfield_delim` and the resulting fields with `delim`.
+///
+/// This is the writer counterpart of `enum_subfields`: it undoes the split
+/// of a record into fields and each field into subfields in one pass.
+///
+/// # Examples
+/// ```
+/// use botao::fields::format_structured_fields;
+/// let record = vec![vec!["10", "20"], vec!["30"]];
+/// assert_eq!(format_structured_fields(b';', b',', &record), "10,20;30");
+/// ```
+pub fn format_structured_fields<T: AsRef<str>>(delim: u8, subfield_delim: u8, record: &[Vec<T>]) -> String {
+    let fields: Vec<String> = record.iter()
+        .map(|subfields| format_fields(subfield_delim, subfields))
+        .collect();
+    format_fields(delim, &fields)
+}
@@ -1,5 +1,8 @@
 use memchr::memchr;
 use failure::Fail;
+use serde::de::{DeserializeOwned, DeserializeSeed, Deserializer, SeqAccess, Visitor};
+use serde::forward_to_deserialize_any;
+use std::fmt;
 use std::marker::PhantomData;
 use std::io;
 
@@ -26,6 +29,96 @@ pub fn next_field(delim: u8, record: &str) -> (&str, &str) {
     }
 }
 
+/// Returns whether `buffer` holds an open (unterminated) `quote`-delimited
+/// span, i.e. an odd number of quote bytes.
+fn is_inside_open_quote(quote: u8, buffer: &[u8]) -> bool {
+    buffer.iter().filter(|&&b| b == quote).count() % 2 == 1
+}
+
+/// Finds the next field in a quoted record, unescaping doubled quotes.
+///
+/// Unlike [`next_field_bytes`](fn.next_field_bytes.html), a field delimiter,
+/// whitespace, or record delimiter inside an open `quote` is treated as
+/// literal content instead of ending the field. The field is returned as an
+/// owned, unescaped buffer since unescaping doubled quotes can shrink it.
+///
+/// Like [`field_slices`](fn.field_slices.html), the returned remainder is
+/// `None` only once there are no more fields, so a trailing empty field
+/// (e.g. the one after the final delimiter in `"10,20,"`) is still produced
+/// instead of being silently dropped.
+fn next_field_quoted(field_delim: u8, quote: u8, record: &[u8]) -> (Vec<u8>, Option<&[u8]>) {
+    let record = trim_bytes(record);
+    if record.first() != Some(&quote) {
+        return match memchr(field_delim, record) {
+            Some(pos) => (trim_bytes(&record[..pos]).to_vec(), Some(&record[pos+1..])),
+            None => (record.to_vec(), None),
+        };
+    }
+    let mut field = Vec::new();
+    let mut i = 1;
+    while let Some(&b) = record.get(i) {
+        if b == quote {
+            if record.get(i + 1) == Some(&quote) {
+                field.push(quote);
+                i += 2;
+            } else {
+                i += 1;
+                break;
+            }
+        } else {
+            field.push(b);
+            i += 1;
+        }
+    }
+    let rest = trim_bytes(&record[i..]);
+    let rest = memchr(field_delim, rest).map(|pos| &rest[pos+1..]);
+    (field, rest)
+}
+
+/// Splits a trimmed record into quoted fields. See
+/// [`next_field_quoted`](fn.next_field_quoted.html).
+fn split_quoted_fields(field_delim: u8, quote: u8, record: &[u8]) -> Vec<Vec<u8>> {
+    let mut fields = Vec::new();
+    let mut rest = Some(trim_bytes(record));
+    while let Some(r) = rest {
+        let (field, remainder) = next_field_quoted(field_delim, quote, r);
+        fields.push(field);
+        rest = remainder;
+    }
+    fields
+}
+
+/// Trims leading and trailing ASCII whitespace from a byte slice.
+fn trim_bytes(buf: &[u8]) -> &[u8] {
+    let is_space = |b: &u8| *b == b' ' || *b == b'\t' || *b == b'\r' || *b == b'\n';
+    let start = buf.iter().position(|b| !is_space(b)).unwrap_or(buf.len());
+    let end = buf.iter().rposition(|b| !is_space(b)).map_or(start, |p| p + 1);
+    &buf[start..end]
+}
+
+/// Finds the next field separated by `delim` in the given raw record.
+///
+/// This is the byte-oriented counterpart of [`next_field`](fn.next_field.html).
+/// It splits on the raw delimiter byte without requiring the record to be
+/// valid UTF-8, which makes it suitable for latin-1 or mixed-encoding data.
+/// The bytes around fields are trimmed the same way `next_field` trims them.
+///
+/// # Examples
+///
+/// ```
+/// use botao::text::next_field_bytes;
+/// let result = next_field_bytes(b',', b"10, 20, 30");
+/// assert_eq!(result, (&b"10"[..], &b" 20, 30"[..]));
+/// ```
+pub fn next_field_bytes(delim: u8, record: &[u8]) -> (&[u8], &[u8]) {
+    let record = trim_bytes(record);
+    if let Some(pos) = memchr(delim, record) {
+        (trim_bytes(&record[0..pos]), &record[pos+1..])
+    } else {
+        (record, &[])
+    }
+}
+
 /// Creates an iterator that returns fields in the given record.
 ///
 /// This function creates an iterator that iterates over the fields.
@@ -73,10 +166,61 @@ impl<'a> Iterator for EnumFields<'a> {
     }
 }
 
+/// Creates an iterator that splits the given record into fields separated
+/// by `field_delim`, and each field into subfields separated by
+/// `subfield_delim`, in one pass.
+///
+/// This promotes the nesting pattern of calling `enum_fields` on the outer
+/// delimiter and then again on every field into a first-class helper.
+///
+/// # Examples
+///
+/// ```
+/// use botao::text::enum_subfields;
+/// let mut iter = enum_subfields(b';', b',', "10, 20; 30");
+/// assert_eq!(iter.next(), Some(vec!["10", "20"]));
+/// assert_eq!(iter.next(), Some(vec!["30"]));
+/// assert_eq!(iter.next(), None);
+/// ```
+pub fn enum_subfields<'a>(field_delim: u8, subfield_delim: u8, record: &'a str) -> EnumSubfields<'a> {
+    EnumSubfields {
+        field_delim,
+        subfield_delim,
+        record: record.trim(),
+        _phantom: PhantomData,
+    }
+}
+
+/// An iterator type created by the function `enum_subfields`.
+///
+/// See the documentation of the function [`enum_subfields`](./fn.enum_subfields.html).
+pub struct EnumSubfields<'a> {
+    field_delim: u8,
+    subfield_delim: u8,
+    record: &'a str,
+    _phantom: PhantomData<&'a str>,
+}
+
+impl<'a> Iterator for EnumSubfields<'a> {
+    type Item = Vec<&'a str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.record.len() == 0 {
+            None
+        } else {
+            let (field, result) = next_field(self.field_delim, self.record);
+            self.record = result;
+            Some(enum_fields(self.subfield_delim, field).collect())
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct DataRecordReaderBuilder<RdType, FdType> {
     record_delimiter: RdType,
     field_delimiter: FdType,
+    quote: Option<u8>,
+    subfield_delimiter: Option<u8>,
 }
 
 impl DataRecordReaderBuilder<(), ()> {
@@ -84,6 +228,8 @@ impl DataRecordReaderBuilder<(), ()> {
         DataRecordReaderBuilder {
             record_delimiter: (),
             field_delimiter: (),
+            quote: None,
+            subfield_delimiter: None,
         }
     }
 }
@@ -94,8 +240,11 @@ impl DataRecordReaderBuilder<u8, u8> {
             stream: stream,
             record_delimiter: self.record_delimiter,
             field_delimiter: self.field_delimiter,
+            quote: self.quote,
+            subfield_delimiter: self.subfield_delimiter,
             buffer: Vec::new(),
             peek_buf: None,
+            position: Position::new(),
         }
     }
 }
@@ -105,6 +254,8 @@ impl<RdType, FdType> DataRecordReaderBuilder<RdType, FdType> {
         DataRecordReaderBuilder {
             record_delimiter: delim,
             field_delimiter: self.field_delimiter,
+            quote: self.quote,
+            subfield_delimiter: self.subfield_delimiter,
         }
     }
 
@@ -112,6 +263,43 @@ impl<RdType, FdType> DataRecordReaderBuilder<RdType, FdType> {
         DataRecordReaderBuilder {
             record_delimiter: self.record_delimiter,
             field_delimiter: delim,
+            quote: self.quote,
+            subfield_delimiter: self.subfield_delimiter,
+        }
+    }
+
+    /// Enables quoted-field parsing using `quote` as the quote character.
+    ///
+    /// While quoting is enabled, `next_record` keeps reading past the
+    /// record delimiter while inside an open quote, and a delimiter,
+    /// whitespace, or record delimiter inside a quoted span is treated as
+    /// literal content. A doubled quote inside a quoted span unescapes to a
+    /// single quote, as in CSV.
+    pub fn quote(self, quote: u8) -> DataRecordReaderBuilder<RdType, FdType> {
+        DataRecordReaderBuilder {
+            record_delimiter: self.record_delimiter,
+            field_delimiter: self.field_delimiter,
+            quote: Some(quote),
+            subfield_delimiter: self.subfield_delimiter,
+        }
+    }
+
+    /// Enables quoted-field parsing using `"` as the quote character. See
+    /// [`quote`](#method.quote) to use a different quote character.
+    pub fn quoting(self) -> DataRecordReaderBuilder<RdType, FdType> {
+        self.quote(b'"')
+    }
+
+    /// Enables structured-field parsing: each field is further split into
+    /// subfields separated by `delim`, and `next_record` returns
+    /// `DataRecord::StructuredFields` instead of `DataRecord::Fields`. See
+    /// [`enum_subfields`](fn.enum_subfields.html).
+    pub fn subfield_delimiter(self, delim: u8) -> DataRecordReaderBuilder<RdType, FdType> {
+        DataRecordReaderBuilder {
+            record_delimiter: self.record_delimiter,
+            field_delimiter: self.field_delimiter,
+            quote: self.quote,
+            subfield_delimiter: Some(delim),
         }
     }
 }
@@ -123,6 +311,191 @@ pub enum ReaderError {
     Io(#[cause] std::io::Error),
     #[fail(display = "From UTF-8 error: {}", _0)]
     FromUTF8(#[cause] std::string::FromUtf8Error),
+    #[fail(display = "UTF-8 error in field {}: {}", _0, _1)]
+    FieldFromUTF8(usize, #[cause] std::str::Utf8Error),
+}
+
+/// Error returned when deserializing a record's fields into a typed value
+/// fails.
+///
+/// `serde::de::Error` requires `std::error::Error`, which `failure::Fail`
+/// does not provide directly, so this type implements `Display` and
+/// `std::error::Error` by hand; `failure`'s blanket impl still picks it up
+/// as a `Fail`.
+#[derive(Debug)]
+pub enum DeserializeError {
+    Field(usize, String),
+    Message(String),
+}
+
+impl fmt::Display for DeserializeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DeserializeError::Field(index, msg) => {
+                write!(f, "error deserializing field {}: {}", index, msg)
+            },
+            DeserializeError::Message(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+impl std::error::Error for DeserializeError {}
+
+impl serde::de::Error for DeserializeError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        DeserializeError::Message(msg.to_string())
+    }
+}
+
+/// Deserializes a record's fields into `T` using `serde`.
+///
+/// The fields are mapped onto the fields of `T` in order, exactly as the
+/// `csv` crate deserializes a record into a `#[derive(Deserialize)]` struct
+/// or tuple. An `Option<T>` field deserializes to `None` when its text is
+/// empty. If a field cannot be parsed into its target type, the returned
+/// error names the offending field's index.
+///
+/// # Examples
+///
+/// ```
+/// use botao::text::deserialize_record;
+/// let fields = vec!["label".to_owned(), "3.14".to_owned(), "42".to_owned()];
+/// let (name, x, n): (String, f64, i64) = deserialize_record(&fields).unwrap();
+/// assert_eq!((name.as_str(), x, n), ("label", 3.14, 42));
+/// ```
+pub fn deserialize_record<T: DeserializeOwned>(fields: &[String]) -> Result<T, DeserializeError> {
+    let mut deserializer = RecordDeserializer { fields, index: 0 };
+    T::deserialize(&mut deserializer)
+}
+
+struct RecordDeserializer<'a> {
+    fields: &'a [String],
+    index: usize,
+}
+
+impl<'a> RecordDeserializer<'a> {
+    fn next_field(&mut self) -> Option<(usize, &'a str)> {
+        let field = self.fields.get(self.index).map(|s| s.as_str());
+        field.map(|field| {
+            let index = self.index;
+            self.index += 1;
+            (index, field)
+        })
+    }
+}
+
+impl<'de, 'a> Deserializer<'de> for &mut RecordDeserializer<'a> {
+    type Error = DeserializeError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_seq(self)
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value, Self::Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct tuple_struct
+        map enum identifier ignored_any
+    }
+}
+
+impl<'de, 'a> SeqAccess<'de> for RecordDeserializer<'a> {
+    type Error = DeserializeError;
+
+    fn next_element_seed<S: DeserializeSeed<'de>>(&mut self, seed: S) -> Result<Option<S::Value>, Self::Error> {
+        match self.next_field() {
+            Some((index, field)) => {
+                let mut deserializer = FieldDeserializer { field, index };
+                seed.deserialize(&mut deserializer).map(Some)
+            },
+            None => Ok(None),
+        }
+    }
+}
+
+struct FieldDeserializer<'a> {
+    field: &'a str,
+    index: usize,
+}
+
+impl<'a> FieldDeserializer<'a> {
+    fn err<T: fmt::Display>(&self, msg: T) -> DeserializeError {
+        DeserializeError::Field(self.index, msg.to_string())
+    }
+}
+
+macro_rules! deserialize_parsed {
+    ($method:ident, $visit:ident, $ty:ty) => {
+        fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+            let value: $ty = self.field.parse().map_err(|e| self.err(e))?;
+            visitor.$visit(value)
+        }
+    };
+}
+
+impl<'de, 'a> Deserializer<'de> for &mut FieldDeserializer<'a> {
+    type Error = DeserializeError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_str(self.field)
+    }
+
+    deserialize_parsed!(deserialize_bool, visit_bool, bool);
+    deserialize_parsed!(deserialize_i8, visit_i8, i8);
+    deserialize_parsed!(deserialize_i16, visit_i16, i16);
+    deserialize_parsed!(deserialize_i32, visit_i32, i32);
+    deserialize_parsed!(deserialize_i64, visit_i64, i64);
+    deserialize_parsed!(deserialize_u8, visit_u8, u8);
+    deserialize_parsed!(deserialize_u16, visit_u16, u16);
+    deserialize_parsed!(deserialize_u32, visit_u32, u32);
+    deserialize_parsed!(deserialize_u64, visit_u64, u64);
+    deserialize_parsed!(deserialize_f32, visit_f32, f32);
+    deserialize_parsed!(deserialize_f64, visit_f64, f64);
+
+    fn deserialize_char<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        let mut chars = self.field.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => visitor.visit_char(c),
+            _ => Err(self.err("expected a single character")),
+        }
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_str(self.field)
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_string(self.field.to_owned())
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        if self.field.is_empty() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    forward_to_deserialize_any! {
+        bytes byte_buf unit unit_struct newtype_struct seq tuple tuple_struct
+        map struct enum identifier ignored_any
+    }
 }
 
 /// `DataRecordReader` provides a function of reading records.
@@ -135,8 +508,62 @@ pub struct DataRecordReader<R: io::BufRead> {
     stream: R,
     record_delimiter: u8,
     field_delimiter: u8,
+    quote: Option<u8>,
+    subfield_delimiter: Option<u8>,
     buffer: Vec<u8>,
     peek_buf: Option<DataRecord>,
+    position: Position,
+}
+
+/// A position within a record stream.
+///
+/// `Position` mirrors the way the `csv` crate keeps position information
+/// alongside record data: the current line, the byte offset from the start
+/// of the stream, and the number of records read so far. It is returned by
+/// [`DataRecordReader::position`](struct.DataRecordReader.html#method.position)
+/// and can be passed back to
+/// [`DataRecordReader::seek`](struct.DataRecordReader.html#method.seek) to
+/// resume reading from a recorded point in a seekable stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Position {
+    line: u64,
+    byte: u64,
+    record: u64,
+}
+
+impl Position {
+    /// Returns the position at the start of a stream: line 1, byte 0,
+    /// record 0.
+    pub fn new() -> Self {
+        Position { line: 1, byte: 0, record: 0 }
+    }
+
+    /// The 1-based line number.
+    pub fn line(&self) -> u64 {
+        self.line
+    }
+
+    /// The byte offset from the start of the stream.
+    pub fn byte(&self) -> u64 {
+        self.byte
+    }
+
+    /// The number of records read so far.
+    pub fn record(&self) -> u64 {
+        self.record
+    }
+}
+
+impl Default for Position {
+    fn default() -> Self {
+        Position::new()
+    }
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}, byte {}, record {}", self.line, self.byte, self.record)
+    }
 }
 
 /// `DataRecord` type represents the records in data files.
@@ -144,6 +571,11 @@ pub struct DataRecordReader<R: io::BufRead> {
 pub enum DataRecord {
     /// A record with fields.
     Fields(Vec<String>),
+    /// A record with fields, each further split into subfields. Produced
+    /// instead of `Fields` when
+    /// [`DataRecordReaderBuilder::subfield_delimiter`](struct.DataRecordReaderBuilder.html#method.subfield_delimiter)
+    /// is set.
+    StructuredFields(Vec<Vec<String>>),
     /// A comment line.
     Comment(String),
     /// A blank line.
@@ -152,6 +584,121 @@ pub enum DataRecord {
     EOF,
 }
 
+/// `ByteRecord` is the raw-byte counterpart of [`DataRecord`](enum.DataRecord.html).
+///
+/// It is produced by [`next_byte_record`](struct.DataRecordReader.html#method.next_byte_record)
+/// and holds the fields of a record as they were read, without any UTF-8
+/// validation. Use [`into_record`](enum.ByteRecord.html#method.into_record)
+/// (or `TryFrom`/`try_into`) to validate the fields lazily once you actually
+/// need `String`s.
+#[derive(Debug)]
+pub enum ByteRecord {
+    /// A record with fields.
+    Fields(Vec<Vec<u8>>),
+    /// A comment line.
+    Comment(Vec<u8>),
+    /// A blank line.
+    Blank,
+    /// The End-Of-File.
+    EOF,
+}
+
+impl ByteRecord {
+    /// Validates the fields of this record as UTF-8 and converts it into a
+    /// [`DataRecord`](enum.DataRecord.html).
+    ///
+    /// Validation happens lazily, field by field; if a field is not valid
+    /// UTF-8 the returned error names its index via
+    /// `ReaderError::FieldFromUTF8`.
+    pub fn into_record(self) -> Result<DataRecord, ReaderError> {
+        match self {
+            ByteRecord::Fields(fields) => {
+                let mut out = Vec::with_capacity(fields.len());
+                for (index, field) in fields.into_iter().enumerate() {
+                    let field = String::from_utf8(field)
+                        .map_err(|e| ReaderError::FieldFromUTF8(index, e.utf8_error()))?;
+                    out.push(field);
+                }
+                Ok(DataRecord::Fields(out))
+            },
+            ByteRecord::Comment(bytes) => {
+                let comment = String::from_utf8(bytes)
+                    .map_err(|e| ReaderError::FieldFromUTF8(0, e.utf8_error()))?;
+                Ok(DataRecord::Comment(comment))
+            },
+            ByteRecord::Blank => Ok(DataRecord::Blank),
+            ByteRecord::EOF => Ok(DataRecord::EOF),
+        }
+    }
+}
+
+impl std::convert::TryFrom<ByteRecord> for DataRecord {
+    type Error = ReaderError;
+
+    fn try_from(record: ByteRecord) -> Result<Self, Self::Error> {
+        record.into_record()
+    }
+}
+
+/// Iterates over the raw field slices of a trimmed record, walking
+/// delimiters with repeated `memchr` calls instead of re-trimming the whole
+/// remainder on every step.
+struct FieldSlices<'a> {
+    delim: u8,
+    rest: Option<&'a [u8]>,
+}
+
+fn field_slices(delim: u8, record: &[u8]) -> FieldSlices<'_> {
+    FieldSlices { delim, rest: Some(record) }
+}
+
+impl<'a> Iterator for FieldSlices<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = self.rest.take()?;
+        match memchr(self.delim, rest) {
+            Some(pos) => {
+                self.rest = Some(&rest[pos+1..]);
+                Some(&rest[..pos])
+            },
+            None => Some(rest),
+        }
+    }
+}
+
+/// An iterator over the field slices of a [`RecordRef::Fields`](enum.RecordRef.html)
+/// record, borrowed directly out of the reader's internal buffer.
+pub struct FieldRefs<'a> {
+    inner: FieldSlices<'a>,
+}
+
+impl<'a> Iterator for FieldRefs<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(trim_bytes)
+    }
+}
+
+/// A borrowed record handed to the closure passed to
+/// [`for_each_record`](struct.DataRecordReader.html#method.for_each_record).
+///
+/// Unlike [`DataRecord`](enum.DataRecord.html) and
+/// [`ByteRecord`](enum.ByteRecord.html), `RecordRef` carries no owned data:
+/// its fields are slices borrowed from the reader's internal buffer for the
+/// duration of the callback.
+pub enum RecordRef<'a> {
+    /// A record with fields.
+    Fields(FieldRefs<'a>),
+    /// A comment line, including its leading `#`.
+    Comment(&'a [u8]),
+    /// A blank line.
+    Blank,
+    /// The End-Of-File.
+    EOF,
+}
+
 impl<R: io::BufRead> DataRecordReader<R> {
     /// Creates a new `DataRecordReader`.
     ///
@@ -162,8 +709,11 @@ impl<R: io::BufRead> DataRecordReader<R> {
             stream: stream,
             record_delimiter: b'\n',
             field_delimiter: b',',
+            quote: None,
+            subfield_delimiter: None,
             buffer: Vec::new(),
             peek_buf: None,
+            position: Position::new(),
         }
     }
 
@@ -175,6 +725,38 @@ impl<R: io::BufRead> DataRecordReader<R> {
         self.field_delimiter = delim;
     }
 
+    /// The quote character used for quoted-field parsing in `next_record`,
+    /// or `None` if quoting is disabled (the default).
+    pub fn quote(&self) -> Option<u8> {
+        self.quote
+    }
+
+    /// Enables or disables quoted-field parsing. See
+    /// [`DataRecordReaderBuilder::quote`](struct.DataRecordReaderBuilder.html#method.quote).
+    pub fn set_quote(&mut self, quote: Option<u8>) {
+        self.quote = quote;
+    }
+
+    /// The subfield delimiter used to further split fields in `next_record`,
+    /// or `None` if structured-field parsing is disabled (the default).
+    pub fn subfield_delimiter(&self) -> Option<u8> {
+        self.subfield_delimiter
+    }
+
+    /// Enables or disables structured-field parsing. See
+    /// [`DataRecordReaderBuilder::subfield_delimiter`](struct.DataRecordReaderBuilder.html#method.subfield_delimiter).
+    pub fn set_subfield_delimiter(&mut self, delim: Option<u8>) {
+        self.subfield_delimiter = delim;
+    }
+
+    /// Returns the current position in the stream.
+    ///
+    /// The position reflects everything read so far, including any record
+    /// buffered by [`peek_record`](#method.peek_record).
+    pub fn position(&self) -> Position {
+        self.position
+    }
+
     pub fn peek_record(&mut self) -> Result<&DataRecord, failure::Error> {
         if self.peek_buf.is_none() {
             let record = self.next_record()?;
@@ -183,36 +765,309 @@ impl<R: io::BufRead> DataRecordReader<R> {
         Ok(self.peek_buf.as_ref().unwrap())
     }
 
+    /// Reads the next record into `self.buffer`, replacing whatever was
+    /// there, and advances `self.position`. Returns whether a record was
+    /// actually read (`false` at EOF).
+    ///
+    /// If quoting is enabled, reading continues past embedded record
+    /// delimiters while the buffer holds an open (unterminated) quote.
+    /// Comment lines (starting with `#`) are exempt from this, since a quote
+    /// character inside a comment is just a character, not the start of a
+    /// quoted field.
+    fn fill_record_buffer(&mut self) -> Result<bool, ReaderError> {
+        self.buffer.clear();
+        let mut result = self.stream.read_until(self.record_delimiter, &mut self.buffer)
+                         .map_err(ReaderError::Io)?;
+        if result == 0 {
+            return Ok(false);
+        }
+        if let Some(quote) = self.quote {
+            while self.buffer[0] != b'#' && is_inside_open_quote(quote, &self.buffer) {
+                let more = self.stream.read_until(self.record_delimiter, &mut self.buffer)
+                                 .map_err(ReaderError::Io)?;
+                if more == 0 {
+                    break;
+                }
+                result += more;
+            }
+        }
+        self.position.byte += result as u64;
+        self.position.record += 1;
+        self.position.line += self.buffer.iter().filter(|&&b| b == self.record_delimiter).count() as u64;
+        Ok(true)
+    }
+
+    /// Wraps a record's fields as `DataRecord::Fields`, or, if a subfield
+    /// delimiter is configured, splits each field into subfields and wraps
+    /// them as `DataRecord::StructuredFields`.
+    ///
+    /// Subfields are split with [`field_slices`](fn.field_slices.html)
+    /// rather than the legacy [`enum_fields`](fn.enum_fields.html), so a
+    /// trailing empty subfield (e.g. the one after the final `subdelim` in
+    /// `"10,20,"`) is preserved, matching how the outer fields are split.
+    fn make_fields_record(&self, fields: Vec<String>) -> DataRecord {
+        match self.subfield_delimiter {
+            Some(subdelim) => {
+                let fields = fields.iter()
+                    .map(|field| {
+                        field_slices(subdelim, field.as_bytes())
+                            .map(|s| std::str::from_utf8(trim_bytes(s))
+                                .expect("subfield bytes are a sub-slice of an already-validated str")
+                                .to_owned())
+                            .collect()
+                    })
+                    .collect();
+                DataRecord::StructuredFields(fields)
+            },
+            None => DataRecord::Fields(fields),
+        }
+    }
+
+    /// Writes a previously peeked `record` back into `self.buffer` so that
+    /// [`record_ref`](#method.record_ref) reproduces it. Used to drain a
+    /// record buffered by [`peek_record`](#method.peek_record) before
+    /// resuming unbuffered reads in [`for_each_record`](#method.for_each_record).
+    ///
+    /// A `StructuredFields` record is flattened back into plain fields by
+    /// joining its subfields with the subfield delimiter (or the field
+    /// delimiter, if none is configured), since `RecordRef` has no
+    /// structured-field variant.
+    fn load_buffer_from_record(&mut self, record: &DataRecord) {
+        self.buffer.clear();
+        match record {
+            DataRecord::Fields(fields) => {
+                self.buffer.extend_from_slice(crate::fields::format_fields(self.field_delimiter, fields).as_bytes());
+            },
+            DataRecord::StructuredFields(fields) => {
+                let subdelim = self.subfield_delimiter.unwrap_or(self.field_delimiter);
+                self.buffer.extend_from_slice(
+                    crate::fields::format_structured_fields(self.field_delimiter, subdelim, fields).as_bytes()
+                );
+            },
+            DataRecord::Comment(comment) => self.buffer.extend_from_slice(comment.as_bytes()),
+            DataRecord::Blank | DataRecord::EOF => {},
+        }
+    }
+
+    /// Interprets the current contents of `self.buffer` as a `RecordRef`,
+    /// borrowing its field slices directly out of the buffer.
+    fn record_ref(&self) -> RecordRef<'_> {
+        if self.buffer[0] == b'#' {
+            RecordRef::Comment(&self.buffer)
+        } else {
+            let rest = trim_bytes(&self.buffer);
+            if rest.is_empty() {
+                RecordRef::Blank
+            } else {
+                RecordRef::Fields(FieldRefs {
+                    inner: field_slices(self.field_delimiter, rest),
+                })
+            }
+        }
+    }
+
+    /// Reads every remaining record, calling `f` with a borrowed
+    /// [`RecordRef`](enum.RecordRef.html) for each one (terminated by a
+    /// final call with `RecordRef::EOF`).
+    ///
+    /// This is the allocation-free counterpart of repeatedly calling
+    /// [`next_record`](#method.next_record): the buffer filled by
+    /// `read_until` is reused across records, and fields are carved out of
+    /// it with `memchr` instead of being copied into owned `String`s. Use
+    /// this when scanning large numeric dumps where the owned API's
+    /// per-record allocations dominate runtime.
+    ///
+    /// A record already buffered by [`peek_record`](#method.peek_record) is
+    /// drained first, so switching from `peek_record` to `for_each_record`
+    /// does not silently drop it.
+    pub fn for_each_record(&mut self, mut f: impl FnMut(RecordRef)) -> Result<(), failure::Error> {
+        if let Some(record) = self.peek_buf.take() {
+            match record {
+                DataRecord::EOF => {
+                    f(RecordRef::EOF);
+                    return Ok(());
+                },
+                DataRecord::Blank => f(RecordRef::Blank),
+                record => {
+                    self.load_buffer_from_record(&record);
+                    f(self.record_ref());
+                },
+            }
+        }
+        loop {
+            if !self.fill_record_buffer()? {
+                f(RecordRef::EOF);
+                return Ok(());
+            }
+            f(self.record_ref());
+        }
+    }
+
+    /// Reads the next record.
+    ///
+    /// If [quoting is enabled](struct.DataRecordReaderBuilder.html#method.quote),
+    /// fields are split honoring quoted spans, unescaping doubled quotes
+    /// along the way; otherwise fields are split plainly, trimming
+    /// whitespace on each field. The quote-aware field splitting happens
+    /// only in this method; [`next_byte_record`](#method.next_byte_record)
+    /// and [`for_each_record`](#method.for_each_record) still merge a
+    /// multi-line quoted record's physical lines into one raw record (since
+    /// that merging lives in `fill_record_buffer`), but split its fields
+    /// plainly, leaving embedded delimiters, newlines, and quote characters
+    /// as literal content instead of unescaping them.
     pub fn next_record(&mut self) -> Result<DataRecord, failure::Error> {
         if let Some(record) = self.peek_buf.take() {
             return Ok(record);
         }
-        let result = self.stream.read_until(self.record_delimiter, &mut self.buffer)
-                         .map_err(|e| ReaderError::Io(e))?;
-        if result == 0 {
-            Ok(DataRecord::EOF)
-        } else {
-            if self.buffer[0] == b'#' {
-                let comment = String::from_utf8(self.buffer.clone())
-                                     .map_err(|e| ReaderError::FromUTF8(e))?;
-                self.buffer.clear();
-                Ok(DataRecord::Comment(comment))
+        if !self.fill_record_buffer()? {
+            return Ok(DataRecord::EOF);
+        }
+        if self.buffer[0] == b'#' {
+            let comment = String::from_utf8(self.buffer.clone())
+                .map_err(ReaderError::FromUTF8)?;
+            return Ok(DataRecord::Comment(comment));
+        }
+        if let Some(quote) = self.quote {
+            let rest = trim_bytes(&self.buffer);
+            return if rest.is_empty() {
+                Ok(DataRecord::Blank)
             } else {
-                let s = String::from_utf8(self.buffer.clone())
-                               .map_err(|e| ReaderError::FromUTF8(e))?;
-                self.buffer.clear();
-                let fields: Vec<String>
-                    = enum_fields(self.field_delimiter, s.as_str()).map(|s| s.to_owned()).collect();
-                if fields.len() == 0 {
-                    Ok(DataRecord::Blank)
-                } else {
-                    Ok(DataRecord::Fields(fields))
+                let mut out = Vec::new();
+                for (index, field) in split_quoted_fields(self.field_delimiter, quote, rest).into_iter().enumerate() {
+                    let field = String::from_utf8(field)
+                        .map_err(|e| ReaderError::FieldFromUTF8(index, e.utf8_error()))?;
+                    out.push(field);
                 }
+                Ok(self.make_fields_record(out))
+            };
+        }
+        match self.record_ref() {
+            RecordRef::EOF => unreachable!("fill_record_buffer reported a record was read"),
+            RecordRef::Comment(_) => unreachable!("comment lines are handled above"),
+            RecordRef::Blank => Ok(DataRecord::Blank),
+            RecordRef::Fields(fields) => {
+                let mut out = Vec::new();
+                for (index, field) in fields.enumerate() {
+                    let field = std::str::from_utf8(field)
+                        .map_err(|e| ReaderError::FieldFromUTF8(index, e))?
+                        .to_owned();
+                    out.push(field);
+                }
+                Ok(self.make_fields_record(out))
+            },
+        }
+    }
+
+    /// Converts a record already buffered by [`peek_record`](#method.peek_record)
+    /// into a `ByteRecord`, for draining it in [`next_byte_record`](#method.next_byte_record).
+    ///
+    /// A `StructuredFields` record is flattened back into plain fields by
+    /// joining its subfields with the subfield delimiter (or the field
+    /// delimiter, if none is configured), since `ByteRecord` has no
+    /// structured-field variant.
+    fn peeked_into_byte_record(&self, record: DataRecord) -> ByteRecord {
+        match record {
+            DataRecord::Fields(fields) => {
+                ByteRecord::Fields(fields.into_iter().map(String::into_bytes).collect())
+            },
+            DataRecord::StructuredFields(fields) => {
+                let subdelim = self.subfield_delimiter.unwrap_or(self.field_delimiter);
+                let fields = fields.iter()
+                    .map(|subfields| crate::fields::format_fields(subdelim, subfields).into_bytes())
+                    .collect();
+                ByteRecord::Fields(fields)
+            },
+            DataRecord::Comment(comment) => ByteRecord::Comment(comment.into_bytes()),
+            DataRecord::Blank => ByteRecord::Blank,
+            DataRecord::EOF => ByteRecord::EOF,
+        }
+    }
+
+    /// Reads the next record as raw bytes, without requiring it to be valid
+    /// UTF-8.
+    ///
+    /// This is the byte-oriented counterpart of
+    /// [`next_record`](#method.next_record). It is useful for parsing
+    /// latin-1 or mixed-encoding data that would otherwise make
+    /// `next_record` fail with `ReaderError::FieldFromUTF8` (or
+    /// `ReaderError::FromUTF8` for a comment line).
+    ///
+    /// A record already buffered by [`peek_record`](#method.peek_record) is
+    /// drained first, so switching from `peek_record` to `next_byte_record`
+    /// does not silently drop it.
+    pub fn next_byte_record(&mut self) -> Result<ByteRecord, failure::Error> {
+        if let Some(record) = self.peek_buf.take() {
+            return Ok(self.peeked_into_byte_record(record));
+        }
+        if !self.fill_record_buffer()? {
+            return Ok(ByteRecord::EOF);
+        }
+        match self.record_ref() {
+            RecordRef::EOF => unreachable!("fill_record_buffer reported a record was read"),
+            RecordRef::Comment(bytes) => Ok(ByteRecord::Comment(bytes.to_vec())),
+            RecordRef::Blank => Ok(ByteRecord::Blank),
+            RecordRef::Fields(fields) => {
+                Ok(ByteRecord::Fields(fields.map(|field| field.to_vec()).collect()))
+            },
+        }
+    }
+
+    /// Reads the next record and deserializes its fields into `T`.
+    ///
+    /// Comment lines are skipped. Returns `Ok(None)` on a blank line or at
+    /// end of file. See [`deserialize_record`](fn.deserialize_record.html)
+    /// for how fields are mapped onto `T`.
+    pub fn deserialize<T: DeserializeOwned>(&mut self) -> Result<Option<T>, failure::Error> {
+        loop {
+            match self.next_record()? {
+                DataRecord::EOF | DataRecord::Blank => return Ok(None),
+                DataRecord::Comment(_) => continue,
+                DataRecord::Fields(fields) => return Ok(Some(deserialize_record(&fields)?)),
+                DataRecord::StructuredFields(_) => return Err(DeserializeError::Message(
+                    "deserialize does not support structured (subfield-delimited) records".to_owned()
+                ).into()),
             }
         }
     }
 }
 
+impl<R: io::BufRead + io::Seek> DataRecordReader<R> {
+    /// Seeks the underlying stream to a position previously returned by
+    /// [`position`](#method.position) and resumes reading from there.
+    ///
+    /// Any record buffered by `peek_record` is discarded.
+    pub fn seek(&mut self, pos: Position) -> Result<(), failure::Error> {
+        self.stream.seek(io::SeekFrom::Start(pos.byte))
+                   .map_err(ReaderError::Io)?;
+        self.buffer.clear();
+        self.peek_buf = None;
+        self.position = pos;
+        Ok(())
+    }
+}
+
+/// Error returned by [`DataBlockReader::next_block`](struct.DataBlockReader.html#method.next_block)
+/// and [`next_block_as`](struct.DataBlockReader.html#method.next_block_as)
+/// when a record fails to parse, annotated with the position of the record
+/// that caused it.
+///
+/// Like [`DeserializeError`](enum.DeserializeError.html), this implements
+/// `Display`/`std::error::Error` by hand so that `failure`'s blanket impl
+/// picks it up as a `Fail`.
+#[derive(Debug)]
+pub struct BlockParseError<E> {
+    pub position: Position,
+    pub cause: E,
+}
+
+impl<E: fmt::Display> fmt::Display for BlockParseError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} (at {})", self.cause, self.position)
+    }
+}
+
+impl<E: Fail> std::error::Error for BlockParseError<E> {}
+
 /// A reader type that reads data blocks in the given file.
 ///
 /// The type `DataBlockReader` is built on `DataRecordReader`.
@@ -266,10 +1121,50 @@ where
                                            .collect::<Result<Vec<T>, _>>();
                     match vec {
                         Ok(vec) => { block.get_or_insert_with(|| Vec::new()).push(vec); },
-                        Err(e) => { return Err(e.into()); },
+                        Err(cause) => {
+                            let position = self.reader.position();
+                            return Err(BlockParseError { position, cause }.into());
+                        },
                     };
                 },
-                _ => panic!("unreachable!"),
+                DataRecord::StructuredFields(_) => return Err(DeserializeError::Message(
+                    "next_block does not support structured (subfield-delimited) records".to_owned()
+                ).into()),
+                DataRecord::EOF | DataRecord::Blank => unreachable!("filtered out by the peek above"),
+            };
+        };
+        Ok(block)
+    }
+
+    /// Returns a next block deserialized into `S`, or `None`.
+    ///
+    /// This is the `serde`-based counterpart of
+    /// [`next_block`](#method.next_block): instead of parsing every field
+    /// into the single homogeneous type `T`, each record's fields are
+    /// deserialized into a struct or tuple `S`. See
+    /// [`deserialize_record`](fn.deserialize_record.html) for the field
+    /// mapping rules.
+    pub fn next_block_as<S: DeserializeOwned>(&mut self) -> Result<Option<Vec<S>>, failure::Error> {
+        let mut block: Option<Vec<S>> = None;
+        loop {
+            let record = self.reader.peek_record()?;
+            match record {
+                DataRecord::EOF | DataRecord::Blank => break,
+                _ => {},
+            };
+            let record = self.reader.next_record()?;
+            match record {
+                DataRecord::Comment(_) => continue,
+                DataRecord::Fields(fields) => {
+                    let value: S = deserialize_record(&fields).map_err(|cause| {
+                        BlockParseError { position: self.reader.position(), cause }
+                    })?;
+                    block.get_or_insert_with(|| Vec::new()).push(value);
+                },
+                DataRecord::StructuredFields(_) => return Err(DeserializeError::Message(
+                    "next_block_as does not support structured (subfield-delimited) records".to_owned()
+                ).into()),
+                DataRecord::EOF | DataRecord::Blank => unreachable!("filtered out by the peek above"),
             };
         };
         Ok(block)
@@ -291,3 +1186,241 @@ where
         Ok(count)
     }
 }
+
+/// Quotes `field` if it contains `field_delim`, `record_delim`, or `quote`,
+/// doubling any embedded `quote` characters, so that it round-trips back
+/// through [`next_field_quoted`](fn.next_field_quoted.html). Fields needing
+/// no escaping are returned unchanged.
+fn quote_field_if_needed(quote: u8, field_delim: u8, record_delim: u8, field: &str) -> String {
+    let bytes = field.as_bytes();
+    let needs_quoting = bytes.iter().any(|&b| b == field_delim || b == record_delim || b == quote);
+    if !needs_quoting {
+        return field.to_owned();
+    }
+    let mut out = String::with_capacity(field.len() + 2);
+    out.push(quote as char);
+    for c in field.chars() {
+        if c as u32 == quote as u32 {
+            out.push(c);
+        }
+        out.push(c);
+    }
+    out.push(quote as char);
+    out
+}
+
+#[derive(Debug)]
+pub struct DataRecordWriterBuilder<RdType, FdType> {
+    record_delimiter: RdType,
+    field_delimiter: FdType,
+    quote: Option<u8>,
+}
+
+impl DataRecordWriterBuilder<(), ()> {
+    pub fn new() -> Self {
+        DataRecordWriterBuilder {
+            record_delimiter: (),
+            field_delimiter: (),
+            quote: None,
+        }
+    }
+}
+
+impl DataRecordWriterBuilder<u8, u8> {
+    pub fn build<W: io::Write>(self, stream: W) -> DataRecordWriter<W> {
+        DataRecordWriter {
+            stream: stream,
+            record_delimiter: self.record_delimiter,
+            field_delimiter: self.field_delimiter,
+            quote: self.quote,
+        }
+    }
+}
+
+impl<RdType, FdType> DataRecordWriterBuilder<RdType, FdType> {
+    pub fn record_delimiter(self, delim: u8) -> DataRecordWriterBuilder<u8, FdType> {
+        DataRecordWriterBuilder {
+            record_delimiter: delim,
+            field_delimiter: self.field_delimiter,
+            quote: self.quote,
+        }
+    }
+
+    pub fn field_delimiter(self, delim: u8) -> DataRecordWriterBuilder<RdType, u8> {
+        DataRecordWriterBuilder {
+            record_delimiter: self.record_delimiter,
+            field_delimiter: delim,
+            quote: self.quote,
+        }
+    }
+
+    /// Enables quote-escaping in `write_fields`, using `quote` as the quote
+    /// character. See [`DataRecordWriter::quote`](struct.DataRecordWriter.html#method.quote)
+    /// for what gets escaped.
+    pub fn quote(self, quote: u8) -> DataRecordWriterBuilder<RdType, FdType> {
+        DataRecordWriterBuilder {
+            record_delimiter: self.record_delimiter,
+            field_delimiter: self.field_delimiter,
+            quote: Some(quote),
+        }
+    }
+
+    /// Enables quote-escaping using `"` as the quote character. See
+    /// [`quote`](#method.quote) to use a different quote character.
+    pub fn quoting(self) -> DataRecordWriterBuilder<RdType, FdType> {
+        self.quote(b'"')
+    }
+}
+
+/// `DataRecordWriter` is the write-side counterpart of
+/// [`DataRecordReader`](struct.DataRecordReader.html).
+///
+/// It writes records one line at a time, using the same notion of a record
+/// delimiter and a field delimiter as the reader, so that a block read with
+/// `DataRecordReader`/`DataBlockReader` can be round-tripped back out.
+#[derive(Debug)]
+pub struct DataRecordWriter<W: io::Write> {
+    stream: W,
+    record_delimiter: u8,
+    field_delimiter: u8,
+    quote: Option<u8>,
+}
+
+impl<W: io::Write> DataRecordWriter<W> {
+    /// Creates a new `DataRecordWriter` with `,` as the field delimiter and
+    /// LF as the record delimiter. Quote-escaping is disabled by default; use
+    /// [`DataRecordWriterBuilder`](struct.DataRecordWriterBuilder.html) to
+    /// enable it.
+    pub fn new(stream: W) -> Self {
+        DataRecordWriter {
+            stream: stream,
+            record_delimiter: b'\n',
+            field_delimiter: b',',
+            quote: None,
+        }
+    }
+
+    pub fn field_delimiter(&self) -> &u8 {
+        &self.field_delimiter
+    }
+
+    pub fn set_field_delimiter(&mut self, delim: u8) {
+        self.field_delimiter = delim;
+    }
+
+    /// Returns the quote character used to escape fields in `write_fields`,
+    /// or `None` if quote-escaping is disabled (the default).
+    pub fn quote(&self) -> Option<u8> {
+        self.quote
+    }
+
+    pub fn set_quote(&mut self, quote: Option<u8>) {
+        self.quote = quote;
+    }
+
+    pub fn into_inner(self) -> W {
+        self.stream
+    }
+
+    /// Writes a single record's fields, joined with the field delimiter and
+    /// terminated with the record delimiter.
+    ///
+    /// If [quote-escaping is enabled](#method.quote), a field containing the
+    /// field delimiter, the record delimiter, or the quote character is
+    /// wrapped in quotes, with any quote characters inside it doubled, so
+    /// that `DataRecordReader::next_record` with matching quoting enabled
+    /// parses it back out as a single field. Without quoting enabled, such a
+    /// field is written as literal, unescaped content, which will not
+    /// round-trip back into the same field.
+    pub fn write_fields<T: AsRef<str>>(&mut self, fields: &[T]) -> Result<(), failure::Error> {
+        let line = match self.quote {
+            Some(quote) => {
+                let quoted: Vec<String> = fields.iter()
+                    .map(|field| quote_field_if_needed(quote, self.field_delimiter, self.record_delimiter, field.as_ref()))
+                    .collect();
+                crate::fields::format_fields(self.field_delimiter, &quoted)
+            },
+            None => crate::fields::format_fields(self.field_delimiter, fields),
+        };
+        self.stream.write_all(line.as_bytes()).map_err(ReaderError::Io)?;
+        self.stream.write_all(&[self.record_delimiter]).map_err(ReaderError::Io)?;
+        Ok(())
+    }
+
+    /// Writes a single structured record, joining each field's subfields
+    /// with `subfield_delim` and the resulting fields with the configured
+    /// field delimiter, terminated with the record delimiter. The write-side
+    /// counterpart of `DataRecord::StructuredFields`.
+    pub fn write_structured_fields<T: AsRef<str>>(&mut self, subfield_delim: u8, fields: &[Vec<T>]) -> Result<(), failure::Error> {
+        let line = crate::fields::format_structured_fields(self.field_delimiter, subfield_delim, fields);
+        self.stream.write_all(line.as_bytes()).map_err(ReaderError::Io)?;
+        self.stream.write_all(&[self.record_delimiter]).map_err(ReaderError::Io)?;
+        Ok(())
+    }
+
+    /// Writes a comment line, adding a leading `#` if `comment` doesn't
+    /// already have one.
+    pub fn write_comment(&mut self, comment: &str) -> Result<(), failure::Error> {
+        if !comment.starts_with('#') {
+            self.stream.write_all(b"#").map_err(ReaderError::Io)?;
+        }
+        self.stream.write_all(comment.as_bytes()).map_err(ReaderError::Io)?;
+        self.stream.write_all(&[self.record_delimiter]).map_err(ReaderError::Io)?;
+        Ok(())
+    }
+
+    /// Writes a blank line, i.e. a bare record delimiter.
+    pub fn write_blank(&mut self) -> Result<(), failure::Error> {
+        self.stream.write_all(&[self.record_delimiter]).map_err(ReaderError::Io)?;
+        Ok(())
+    }
+}
+
+/// A writer type that writes data blocks, the write-side counterpart of
+/// [`DataBlockReader`](struct.DataBlockReader.html).
+///
+/// A data block is written as a contiguous series of records, followed by
+/// a configurable number of blank separator lines (one by default).
+#[derive(Debug)]
+pub struct DataBlockWriter<T, W: io::Write> {
+    writer: DataRecordWriter<W>,
+    blank_lines: usize,
+    _phantom: PhantomData<fn() -> T>,
+}
+
+impl<T, W> DataBlockWriter<T, W>
+where
+    W: io::Write,
+    T: std::string::ToString,
+{
+    pub fn new(writer: DataRecordWriter<W>) -> Self {
+        DataBlockWriter {
+            writer: writer,
+            blank_lines: 1,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Sets the number of blank lines written between blocks by
+    /// `write_block`. The default is 1.
+    pub fn set_blank_lines(&mut self, count: usize) {
+        self.blank_lines = count;
+    }
+
+    pub fn into_inner(self) -> DataRecordWriter<W> {
+        self.writer
+    }
+
+    /// Writes a block of rows, followed by the configured number of blank
+    /// separator lines.
+    pub fn write_block(&mut self, block: &[Vec<T>]) -> Result<(), failure::Error> {
+        for row in block {
+            let fields: Vec<String> = row.iter().map(T::to_string).collect();
+            self.writer.write_fields(&fields)?;
+        }
+        for _ in 0..self.blank_lines {
+            self.writer.write_blank()?;
+        }
+        Ok(())
+    }
+}
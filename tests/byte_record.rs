@@ -0,0 +1,54 @@
+use botao::text::{DataRecordReader, ByteRecord, DataRecord};
+use std::convert::TryInto;
+use std::io::Cursor;
+
+#[test]
+fn test_next_byte_record_accepts_invalid_utf8() {
+    let mut data: Vec<u8> = Vec::new();
+    data.extend_from_slice(b"10,");
+    data.extend_from_slice(&[0xff, 0xfe]);
+    data.push(b'\n');
+    data.extend_from_slice(b"20, 30\n");
+
+    let rdr = DataRecordReader::new(Cursor::new(data));
+    let mut rdr = rdr;
+
+    let record = rdr.next_byte_record().unwrap();
+    match record {
+        ByteRecord::Fields(fields) => {
+            assert_eq!(fields, vec![b"10".to_vec(), vec![0xff, 0xfe]]);
+        },
+        other => panic!("expected Fields, got {:?}", other),
+    }
+
+    let record = rdr.next_byte_record().unwrap();
+    match record {
+        ByteRecord::Fields(fields) => {
+            assert_eq!(fields, vec![b"20".to_vec(), b"30".to_vec()]);
+        },
+        other => panic!("expected Fields, got {:?}", other),
+    }
+
+    assert!(matches!(rdr.next_byte_record().unwrap(), ByteRecord::EOF));
+}
+
+#[test]
+fn test_byte_record_into_record_reports_field_index() {
+    let mut data: Vec<u8> = Vec::new();
+    data.extend_from_slice(b"10,");
+    data.extend_from_slice(&[0xff, 0xfe]);
+    data.push(b'\n');
+
+    let mut rdr = DataRecordReader::new(Cursor::new(data));
+    let record = rdr.next_byte_record().unwrap();
+    let err = record.into_record().unwrap_err();
+    assert_eq!(format!("{}", err), "UTF-8 error in field 1: invalid utf-8 sequence of 1 bytes from index 0");
+
+    let mut rdr = DataRecordReader::new(Cursor::new(b"10, 20\n".to_vec()));
+    let record = rdr.next_byte_record().unwrap();
+    let record: DataRecord = record.try_into().unwrap();
+    match record {
+        DataRecord::Fields(fields) => assert_eq!(fields, vec!["10", "20"]),
+        other => panic!("expected Fields, got {:?}", other),
+    }
+}
@@ -0,0 +1,53 @@
+use botao::text::{DataRecordReader, DataBlockReader};
+use serde::Deserialize;
+use std::io::Cursor;
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Row {
+    label: String,
+    x: f64,
+    n: i64,
+    flag: Option<bool>,
+}
+
+#[test]
+fn test_deserialize_maps_positional_fields_and_handles_option() {
+    let data =
+"label, 3.50, 42, true
+other, 2.5, 7,
+";
+    let rdr = DataRecordReader::new(Cursor::new(data.as_bytes().to_vec()));
+    let mut rdr = rdr;
+
+    let row: Row = rdr.deserialize().unwrap().unwrap();
+    assert_eq!(row, Row { label: "label".to_owned(), x: 3.50, n: 42, flag: Some(true) });
+
+    let row: Row = rdr.deserialize().unwrap().unwrap();
+    assert_eq!(row, Row { label: "other".to_owned(), x: 2.5, n: 7, flag: None });
+
+    let row: Option<Row> = rdr.deserialize().unwrap();
+    assert_eq!(row, None);
+}
+
+#[test]
+fn test_deserialize_reports_field_index_on_parse_failure() {
+    let rdr = DataRecordReader::new(Cursor::new(b"label, notanumber, 42, true\n".to_vec()));
+    let mut rdr = rdr;
+    let err = rdr.deserialize::<Row>().unwrap_err();
+    assert!(format!("{}", err).contains("field 1"));
+}
+
+#[test]
+fn test_next_block_as_deserializes_a_whole_block() {
+    let data =
+"label, 3.50, 42, true
+other, 2.5, 7, false
+";
+    let rdr = DataRecordReader::new(Cursor::new(data.as_bytes().to_vec()));
+    let mut rdr = DataBlockReader::<i64, _>::new(rdr);
+    let block = rdr.next_block_as::<Row>().unwrap().unwrap();
+    assert_eq!(block, vec![
+        Row { label: "label".to_owned(), x: 3.50, n: 42, flag: Some(true) },
+        Row { label: "other".to_owned(), x: 2.5, n: 7, flag: Some(false) },
+    ]);
+}
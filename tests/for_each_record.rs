@@ -0,0 +1,74 @@
+use botao::text::{DataRecordReader, RecordRef};
+use std::io::Cursor;
+
+#[test]
+fn test_for_each_record_visits_every_record() {
+    let data = "10, 20\n# a comment\n\n30, 40\n";
+    let mut rdr = DataRecordReader::new(Cursor::new(data.as_bytes().to_vec()));
+
+    let mut seen = Vec::new();
+    rdr.for_each_record(|r| {
+        let entry = match r {
+            RecordRef::Fields(fields) => {
+                let fields: Vec<String> = fields.map(|f| String::from_utf8_lossy(f).into_owned()).collect();
+                format!("Fields({:?})", fields)
+            },
+            RecordRef::Comment(bytes) => format!("Comment({:?})", String::from_utf8_lossy(bytes)),
+            RecordRef::Blank => "Blank".to_owned(),
+            RecordRef::EOF => "EOF".to_owned(),
+        };
+        seen.push(entry);
+    }).unwrap();
+
+    assert_eq!(seen, vec![
+        "Fields([\"10\", \"20\"])".to_owned(),
+        "Comment(\"# a comment\\n\")".to_owned(),
+        "Blank".to_owned(),
+        "Fields([\"30\", \"40\"])".to_owned(),
+        "EOF".to_owned(),
+    ]);
+}
+
+#[test]
+fn test_for_each_record_drains_a_peeked_record_first() {
+    let data = "10, 20\n30, 40\n";
+    let mut rdr = DataRecordReader::new(Cursor::new(data.as_bytes().to_vec()));
+
+    rdr.peek_record().unwrap();
+
+    let mut rows = Vec::new();
+    rdr.for_each_record(|r| {
+        if let RecordRef::Fields(fields) = r {
+            rows.push(fields.map(|f| String::from_utf8_lossy(f).into_owned()).collect::<Vec<_>>());
+        }
+    }).unwrap();
+
+    assert_eq!(rows, vec![vec!["10", "20"], vec!["30", "40"]]);
+}
+
+#[test]
+fn test_for_each_record_drains_a_peeked_blank_record_first() {
+    let data = "\n10, 20\n";
+    let mut rdr = DataRecordReader::new(Cursor::new(data.as_bytes().to_vec()));
+
+    rdr.peek_record().unwrap();
+
+    let mut seen = Vec::new();
+    rdr.for_each_record(|r| {
+        let entry = match r {
+            RecordRef::Fields(fields) => {
+                format!("Fields({:?})", fields.map(|f| String::from_utf8_lossy(f).into_owned()).collect::<Vec<_>>())
+            },
+            RecordRef::Comment(bytes) => format!("Comment({:?})", String::from_utf8_lossy(bytes)),
+            RecordRef::Blank => "Blank".to_owned(),
+            RecordRef::EOF => "EOF".to_owned(),
+        };
+        seen.push(entry);
+    }).unwrap();
+
+    assert_eq!(seen, vec![
+        "Blank".to_owned(),
+        "Fields([\"10\", \"20\"])".to_owned(),
+        "EOF".to_owned(),
+    ]);
+}
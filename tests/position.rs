@@ -0,0 +1,48 @@
+use botao::text::{DataRecordReader, DataRecord, DataBlockReader};
+use std::io::Cursor;
+
+#[test]
+fn test_position_tracks_line_byte_and_record_count() {
+    let data = "10, 20\n30, 40\n50, 60\n";
+    let mut rdr = DataRecordReader::new(Cursor::new(data.as_bytes().to_vec()));
+
+    let pos = rdr.position();
+    assert_eq!((pos.line(), pos.byte(), pos.record()), (1, 0, 0));
+
+    rdr.next_record().unwrap();
+    let pos = rdr.position();
+    assert_eq!((pos.line(), pos.byte(), pos.record()), (2, 7, 1));
+
+    rdr.next_record().unwrap();
+    let pos = rdr.position();
+    assert_eq!((pos.line(), pos.byte(), pos.record()), (3, 14, 2));
+}
+
+#[test]
+fn test_seek_resumes_reading_from_a_recorded_position() {
+    let data = "10, 20\n30, 40\n50, 60\n";
+    let mut rdr = DataRecordReader::new(Cursor::new(data.as_bytes().to_vec()));
+
+    rdr.next_record().unwrap();
+    let pos = rdr.position();
+
+    rdr.next_record().unwrap();
+    rdr.next_record().unwrap();
+    assert!(matches!(rdr.next_record().unwrap(), DataRecord::EOF));
+
+    rdr.seek(pos).unwrap();
+    let record = rdr.next_record().unwrap();
+    match record {
+        DataRecord::Fields(fields) => assert_eq!(fields, vec!["30", "40"]),
+        other => panic!("expected Fields, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_block_parse_error_reports_position() {
+    let data = "0, 0\n1, x\n";
+    let rdr = DataRecordReader::new(Cursor::new(data.as_bytes().to_vec()));
+    let mut rdr = DataBlockReader::<i64, _>::new(rdr);
+    let err = rdr.next_block().unwrap_err();
+    assert!(format!("{}", err).contains("line 3"));
+}
@@ -0,0 +1,68 @@
+use botao::text::{DataRecordReaderBuilder, DataRecord};
+use std::io::Cursor;
+
+#[test]
+fn test_quoted_field_with_embedded_delimiter_and_newline() {
+    let data = "10,\"a, b\nc\",20\n";
+    let mut rdr = DataRecordReaderBuilder::new()
+        .record_delimiter(b'\n')
+        .field_delimiter(b',')
+        .quoting()
+        .build(Cursor::new(data.as_bytes().to_vec()));
+
+    match rdr.next_record().unwrap() {
+        DataRecord::Fields(fields) => assert_eq!(fields, vec!["10", "a, b\nc", "20"]),
+        other => panic!("expected Fields, got {:?}", other),
+    }
+    assert!(matches!(rdr.next_record().unwrap(), DataRecord::EOF));
+}
+
+#[test]
+fn test_quoting_preserves_a_trailing_empty_field_like_the_unquoted_path() {
+    let data = "10,20,\n";
+    let mut rdr = DataRecordReaderBuilder::new()
+        .record_delimiter(b'\n')
+        .field_delimiter(b',')
+        .quoting()
+        .build(Cursor::new(data.as_bytes().to_vec()));
+
+    match rdr.next_record().unwrap() {
+        DataRecord::Fields(fields) => assert_eq!(fields, vec!["10", "20", ""]),
+        other => panic!("expected Fields, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_doubled_quote_unescapes_to_a_single_quote() {
+    let data = "\"say \"\"hi\"\"\"\n";
+    let mut rdr = DataRecordReaderBuilder::new()
+        .record_delimiter(b'\n')
+        .field_delimiter(b',')
+        .quoting()
+        .build(Cursor::new(data.as_bytes().to_vec()));
+
+    match rdr.next_record().unwrap() {
+        DataRecord::Fields(fields) => assert_eq!(fields, vec!["say \"hi\""]),
+        other => panic!("expected Fields, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_comment_line_with_odd_quote_count_does_not_swallow_the_next_record() {
+    let data = "# say \"hello\nfield1,field2\n";
+    let mut rdr = DataRecordReaderBuilder::new()
+        .record_delimiter(b'\n')
+        .field_delimiter(b',')
+        .quoting()
+        .build(Cursor::new(data.as_bytes().to_vec()));
+
+    match rdr.next_record().unwrap() {
+        DataRecord::Comment(comment) => assert_eq!(comment, "# say \"hello\n"),
+        other => panic!("expected Comment, got {:?}", other),
+    }
+    match rdr.next_record().unwrap() {
+        DataRecord::Fields(fields) => assert_eq!(fields, vec!["field1", "field2"]),
+        other => panic!("expected Fields, got {:?}", other),
+    }
+    assert!(matches!(rdr.next_record().unwrap(), DataRecord::EOF));
+}
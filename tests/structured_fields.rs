@@ -0,0 +1,62 @@
+use botao::text::{DataRecordReaderBuilder, DataRecord, DataBlockReader, enum_subfields};
+use std::io::Cursor;
+
+#[test]
+fn test_subfield_delimiter_splits_fields_into_structured_fields() {
+    let data = "a:b,c:d\n";
+    let mut rdr = DataRecordReaderBuilder::new()
+        .record_delimiter(b'\n')
+        .field_delimiter(b',')
+        .subfield_delimiter(b':')
+        .build(Cursor::new(data.as_bytes().to_vec()));
+
+    match rdr.next_record().unwrap() {
+        DataRecord::StructuredFields(fields) => {
+            assert_eq!(fields, vec![
+                vec!["a".to_owned(), "b".to_owned()],
+                vec!["c".to_owned(), "d".to_owned()],
+            ]);
+        },
+        other => panic!("expected StructuredFields, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_subfield_delimiter_preserves_a_trailing_empty_subfield() {
+    let data = "10:20:,30\n";
+    let mut rdr = DataRecordReaderBuilder::new()
+        .record_delimiter(b'\n')
+        .field_delimiter(b',')
+        .subfield_delimiter(b':')
+        .build(Cursor::new(data.as_bytes().to_vec()));
+
+    match rdr.next_record().unwrap() {
+        DataRecord::StructuredFields(fields) => {
+            assert_eq!(fields, vec![
+                vec!["10".to_owned(), "20".to_owned(), "".to_owned()],
+                vec!["30".to_owned()],
+            ]);
+        },
+        other => panic!("expected StructuredFields, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_enum_subfields_iterates_fields_and_subfields() {
+    let entries: Vec<Vec<&str>> = enum_subfields(b',', b':', "a:b,c:d").collect();
+    assert_eq!(entries, vec![vec!["a", "b"], vec!["c", "d"]]);
+}
+
+#[test]
+fn test_next_block_returns_an_error_instead_of_panicking_on_structured_fields() {
+    let data = "a:b,c:d\n";
+    let rdr = DataRecordReaderBuilder::new()
+        .record_delimiter(b'\n')
+        .field_delimiter(b',')
+        .subfield_delimiter(b':')
+        .build(Cursor::new(data.as_bytes().to_vec()));
+    let mut rdr = DataBlockReader::<i64, _>::new(rdr);
+
+    let err = rdr.next_block().unwrap_err();
+    assert!(format!("{}", err).contains("structured"));
+}
@@ -0,0 +1,92 @@
+use botao::text::{DataRecordWriter, DataRecordWriterBuilder, DataBlockWriter, DataRecordReader, DataRecordReaderBuilder, DataRecord};
+use std::io::Cursor;
+
+#[test]
+fn test_write_fields_comment_and_blank() {
+    let mut wtr = DataRecordWriter::new(Vec::new());
+    wtr.write_fields(&["10", "20"]).unwrap();
+    wtr.write_comment("a comment").unwrap();
+    wtr.write_comment("# already tagged").unwrap();
+    wtr.write_blank().unwrap();
+
+    let out = wtr.into_inner();
+    assert_eq!(out, b"10,20\n#a comment\n# already tagged\n\n".to_vec());
+}
+
+#[test]
+fn test_write_structured_fields() {
+    let mut wtr = DataRecordWriter::new(Vec::new());
+    wtr.write_structured_fields(b':', &[vec!["a", "b"], vec!["c"]]).unwrap();
+
+    let out = wtr.into_inner();
+    assert_eq!(out, b"a:b,c\n".to_vec());
+}
+
+#[test]
+fn test_block_writer_separates_blocks_with_configured_blank_lines() {
+    let wtr = DataRecordWriter::new(Vec::new());
+    let mut wtr = DataBlockWriter::<i64, _>::new(wtr);
+    wtr.set_blank_lines(2);
+
+    wtr.write_block(&[vec![1, 2], vec![3, 4]]).unwrap();
+    wtr.write_block(&[vec![5, 6]]).unwrap();
+
+    let out = wtr.into_inner().into_inner();
+    assert_eq!(out, b"1,2\n3,4\n\n\n5,6\n\n\n".to_vec());
+}
+
+#[test]
+fn test_write_fields_without_quoting_does_not_escape_embedded_delimiters() {
+    let mut wtr = DataRecordWriter::new(Vec::new());
+    wtr.write_fields(&["a, b", "c"]).unwrap();
+
+    let out = wtr.into_inner();
+    assert_eq!(out, b"a, b,c\n".to_vec());
+}
+
+#[test]
+fn test_write_fields_with_quoting_escapes_and_round_trips_an_embedded_delimiter() {
+    let mut wtr = DataRecordWriterBuilder::new()
+        .record_delimiter(b'\n')
+        .field_delimiter(b',')
+        .quoting()
+        .build(Vec::new());
+    wtr.write_fields(&["a, b\nc", "10"]).unwrap();
+    let out = wtr.into_inner();
+
+    let mut rdr = DataRecordReaderBuilder::new()
+        .record_delimiter(b'\n')
+        .field_delimiter(b',')
+        .quoting()
+        .build(Cursor::new(out));
+    match rdr.next_record().unwrap() {
+        DataRecord::Fields(fields) => assert_eq!(fields, vec!["a, b\nc", "10"]),
+        other => panic!("expected Fields, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_writer_output_round_trips_through_reader() {
+    let mut wtr = DataRecordWriter::new(Vec::new());
+    wtr.write_fields(&["10", "20"]).unwrap();
+    wtr.write_comment("a comment").unwrap();
+    wtr.write_blank().unwrap();
+    wtr.write_fields(&["30", "40"]).unwrap();
+    let out = wtr.into_inner();
+
+    let mut rdr = DataRecordReader::new(Cursor::new(out));
+    match rdr.next_record().unwrap() {
+        DataRecord::Fields(fields) => assert_eq!(fields, vec!["10", "20"]),
+        other => panic!("expected Fields, got {:?}", other),
+    }
+    match rdr.next_record().unwrap() {
+        DataRecord::Comment(comment) => assert_eq!(comment, "#a comment\n"),
+        other => panic!("expected Comment, got {:?}", other),
+    }
+    assert!(matches!(rdr.next_record().unwrap(), DataRecord::Blank));
+    match rdr.next_record().unwrap() {
+        DataRecord::Fields(fields) => assert_eq!(fields, vec!["30", "40"]),
+        other => panic!("expected Fields, got {:?}", other),
+    }
+    assert!(matches!(rdr.next_record().unwrap(), DataRecord::EOF));
+}